@@ -0,0 +1,11 @@
+//! Shared hashing helpers used to bind proof fixtures to their verifying key.
+
+use sha2::{Digest as _, Sha256};
+
+/// SHA-256 digest, used for `SP1VerifyingKey::hash_bytes32` (the `vkey_hash`
+/// commitment stored on every [`crate::ProofFixture`], regardless of system).
+pub(crate) fn sha256(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hasher.finalize().into()
+}
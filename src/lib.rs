@@ -3,6 +3,22 @@ use std::fs;
 use std::error::Error;
 use serde::{Deserialize, Serialize};
 
+mod digest;
+mod loader;
+mod proof_serde;
+mod solidity;
+#[cfg(feature = "sp1")]
+mod sp1_integration;
+mod verify;
+
+pub use loader::{load_proof_fixture, load_proof_fixture_with, LoadError};
+pub use solidity::{create_solidity_fixture, SolidityFixture, SolidityFixtureError};
+#[cfg(feature = "sp1")]
+pub use sp1_integration::create_fixture_from_elf;
+#[cfg(feature = "sp1")]
+pub use sp1_sdk::SP1Stdin;
+pub use verify::{verify_proof_fixture, verify_proof_fixture_file, VerificationError};
+
 // Type definitions compatible with SP1 SDK
 // These are simplified versions that match the SP1 SDK interface
 
@@ -24,6 +40,18 @@ pub struct SP1VerifyingKey {
     pub vk: Vec<u8>,
 }
 
+impl SP1VerifyingKey {
+    /// Returns a stable 32-byte commitment over this verifying key.
+    ///
+    /// The same verifying key always hashes to the same `bytes32`, so
+    /// verifiers and on-chain contracts can reference a program by this
+    /// single identifier instead of the full key bytes (the `HashableKey`
+    /// concept used by SP1).
+    pub fn hash_bytes32(&self) -> [u8; 32] {
+        digest::sha256(&self.vk)
+    }
+}
+
 /// Proof system types supported by SP1
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ProofSystem {
@@ -36,16 +64,27 @@ pub enum ProofSystem {
 }
 
 /// Proof fixture structure for serialization
+///
+/// `proof`, `public_values`, and `vk` are serialized as `0x`-prefixed hex
+/// strings rather than JSON arrays of integers, which keeps fixture files
+/// small and diff-friendly for real (hundreds-of-KB) proofs. Deserialization
+/// still accepts the legacy array-of-integers form.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProofFixture {
     /// The proof data with public values
+    #[serde(with = "proof_serde")]
     pub proof: Vec<u8>,
     /// The public values
+    #[serde(with = "proof_serde")]
     pub public_values: Vec<u8>,
     /// The verifying key
+    #[serde(with = "proof_serde")]
     pub vk: Vec<u8>,
     /// The proof system used
     pub system: ProofSystem,
+    /// Stable 32-byte commitment over `vk`, see [`SP1VerifyingKey::hash_bytes32`]
+    #[serde(with = "proof_serde")]
+    pub vkey_hash: Vec<u8>,
 }
 
 /// Creates a proof fixture file from SP1 proof data
@@ -101,6 +140,7 @@ pub fn create_proof_fixture(
         public_values: proof.public_values.clone(),
         vk: vk.vk.clone(),
         system,
+        vkey_hash: vk.hash_bytes32().to_vec(),
     };
 
     // Generate filename based on proof system
@@ -117,6 +157,7 @@ pub fn create_proof_fixture(
     fs::write(&fixture_path, json)?;
 
     println!("Proof fixture created at: {}", fixture_path.display());
+    println!("Verifying key hash: 0x{}", hex::encode(&fixture.vkey_hash));
 
     Ok(())
 }
@@ -142,11 +183,13 @@ mod tests {
 
     #[test]
     fn test_proof_fixture_structure() {
+        let vk = SP1VerifyingKey { vk: vec![7, 8, 9] };
         let fixture = ProofFixture {
             proof: vec![1, 2, 3],
             public_values: vec![4, 5, 6],
-            vk: vec![7, 8, 9],
+            vk: vk.vk.clone(),
             system: ProofSystem::Plonk,
+            vkey_hash: vk.hash_bytes32().to_vec(),
         };
 
         let json = serde_json::to_string(&fixture).unwrap();
@@ -156,5 +199,32 @@ mod tests {
         assert_eq!(fixture.public_values, deserialized.public_values);
         assert_eq!(fixture.vk, deserialized.vk);
         assert_eq!(fixture.system, deserialized.system);
+        assert_eq!(fixture.vkey_hash, deserialized.vkey_hash);
+    }
+
+    #[test]
+    fn test_create_then_verify_round_trip() {
+        let proof = SP1ProofWithPublicValues {
+            proof: vec![1, 2, 3, 4],
+            public_values: vec![5, 6, 7, 8],
+        };
+        let vk = SP1VerifyingKey { vk: vec![9, 10, 11, 12] };
+
+        create_proof_fixture(&proof, &vk, ProofSystem::Groth16).unwrap();
+        let loaded = load_proof_fixture("fixtures/proof_fixture_groth16.json").unwrap();
+
+        assert!(verify_proof_fixture(&loaded).unwrap());
+    }
+
+    #[test]
+    fn test_vkey_hash_bytes32_is_stable() {
+        let vk = SP1VerifyingKey { vk: vec![1, 2, 3, 4] };
+
+        let first = vk.hash_bytes32();
+        let second = vk.hash_bytes32();
+        assert_eq!(first, second);
+
+        let other = SP1VerifyingKey { vk: vec![1, 2, 3, 5] };
+        assert_ne!(first, other.hash_bytes32());
     }
 }
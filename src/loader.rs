@@ -0,0 +1,135 @@
+//! Loading and structural validation of stored [`ProofFixture`]s.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::ProofFixture;
+
+/// Errors produced while validating a loaded [`ProofFixture`].
+#[derive(Debug)]
+pub enum LoadError {
+    /// `field` is required to be non-empty but was empty.
+    EmptyField(&'static str),
+    /// `vk` does not decode into a well-formed verifying key.
+    InvalidPointEncoding(&'static str),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::EmptyField(field) => write!(f, "`{field}` must not be empty"),
+            LoadError::InvalidPointEncoding(field) => {
+                write!(f, "`{field}` does not decode into a well-formed verifying key")
+            }
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+impl ProofFixture {
+    /// Checks that this fixture is internally well-formed before it is used.
+    ///
+    /// Always rejects empty `proof`/`public_values`/`vk` fields. When
+    /// `verify_point_encodings` is `true`, also checks that `vk` decodes into
+    /// a well-formed verifying key, mirroring how zcash's
+    /// `SpendParameters::read` takes a `verify_point_encodings` flag so
+    /// callers who already trust a file's hash can skip the expensive check.
+    ///
+    /// This crate doesn't mandate a single proof byte-layout across
+    /// `system`s (`create_proof_fixture` accepts arbitrary proof bytes), so
+    /// unlike [`crate::verify_proof_fixture`] this does not reject fixtures
+    /// by proof length; that binding is checked at verification time.
+    pub fn validate_structure(&self, verify_point_encodings: bool) -> Result<(), LoadError> {
+        if self.proof.is_empty() {
+            return Err(LoadError::EmptyField("proof"));
+        }
+        if self.public_values.is_empty() {
+            return Err(LoadError::EmptyField("public_values"));
+        }
+        if self.vk.is_empty() {
+            return Err(LoadError::EmptyField("vk"));
+        }
+
+        if verify_point_encodings {
+            check_vkey_point_encodings(&self.vk)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that `vk` decodes into a well-formed verifying key.
+///
+/// Without the `sp1` feature there is no real verifying-key decoder linked
+/// into the crate, so this is a no-op; enabling `sp1` upgrades it to
+/// actually `bincode`-decoding `vk` as `sp1_sdk::SP1VerifyingKey` and
+/// rejecting bytes that don't round-trip.
+#[cfg_attr(not(feature = "sp1"), allow(unused_variables))]
+fn check_vkey_point_encodings(vk: &[u8]) -> Result<(), LoadError> {
+    #[cfg(feature = "sp1")]
+    {
+        bincode::deserialize::<sp1_sdk::SP1VerifyingKey>(vk)
+            .map_err(|_| LoadError::InvalidPointEncoding("vk"))?;
+    }
+
+    Ok(())
+}
+
+/// Loads a [`ProofFixture`] from `path`, validating its verifying key's point
+/// encodings.
+///
+/// Equivalent to `load_proof_fixture_with(path, true)`.
+pub fn load_proof_fixture(path: impl AsRef<Path>) -> Result<ProofFixture, Box<dyn Error>> {
+    load_proof_fixture_with(path, true)
+}
+
+/// Loads a [`ProofFixture`] from `path`, optionally skipping the (more
+/// expensive) verifying-key point-encoding check.
+///
+/// Set `verify_point_encodings` to `false` only when the caller already
+/// trusts the file, e.g. because its hash was checked separately.
+pub fn load_proof_fixture_with(
+    path: impl AsRef<Path>,
+    verify_point_encodings: bool,
+) -> Result<ProofFixture, Box<dyn Error>> {
+    let json = fs::read_to_string(path)?;
+    let fixture: ProofFixture = serde_json::from_str(&json)?;
+    fixture.validate_structure(verify_point_encodings)?;
+    Ok(fixture)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProofSystem;
+
+    fn valid_fixture() -> ProofFixture {
+        ProofFixture {
+            proof: vec![1, 2, 3, 4],
+            public_values: vec![1, 2, 3],
+            vk: vec![9, 10, 11, 12],
+            system: ProofSystem::Groth16,
+            vkey_hash: vec![0u8; 32],
+        }
+    }
+
+    #[test]
+    fn accepts_the_documented_example_shaped_fixture() {
+        // Mirrors the short proof/vk used in `create_proof_fixture`'s doc example:
+        // the loader must be able to round-trip whatever the writer produces.
+        assert!(valid_fixture().validate_structure(true).is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_fields() {
+        let mut fixture = valid_fixture();
+        fixture.public_values = vec![];
+        assert!(matches!(
+            fixture.validate_structure(true),
+            Err(LoadError::EmptyField("public_values"))
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+//! Hex-string (de)serialization for fixture byte fields.
+//!
+//! Proof, public-values, and verifying-key bytes can run into the hundreds
+//! of kilobytes for real proofs, and encoding them as JSON arrays of
+//! integers makes fixture files enormous and unreadable. This module
+//! hex-encodes each `Vec<u8>` to a `0x`-prefixed string on write, and
+//! accepts either that hex form or the legacy array-of-integers form on
+//! read so existing fixtures keep loading.
+
+use serde::de::{Deserializer, Error as DeError};
+use serde::ser::Serializer;
+use serde::Deserialize;
+
+pub(crate) fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BytesForm {
+        Hex(String),
+        Array(Vec<u8>),
+    }
+
+    match BytesForm::deserialize(deserializer)? {
+        BytesForm::Array(bytes) => Ok(bytes),
+        BytesForm::Hex(s) => {
+            let stripped = s.strip_prefix("0x").unwrap_or(&s);
+            hex::decode(stripped).map_err(DeError::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(with = "super")]
+        bytes: Vec<u8>,
+    }
+
+    #[test]
+    fn round_trips_through_hex() {
+        let wrapper = Wrapper { bytes: vec![0xde, 0xad, 0xbe, 0xef] };
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, r#"{"bytes":"0xdeadbeef"}"#);
+
+        let decoded: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.bytes, wrapper.bytes);
+    }
+
+    #[test]
+    fn accepts_legacy_array_form() {
+        let decoded: Wrapper = serde_json::from_str(r#"{"bytes":[222,173,190,239]}"#).unwrap();
+        assert_eq!(decoded.bytes, vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+}
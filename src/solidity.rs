@@ -0,0 +1,159 @@
+//! EVM (Solidity) verifier calldata fixtures.
+//!
+//! Smart-contract verifiers such as the Taiko/Raiko SP1 verifier expect a
+//! `verifyProof(bytes32 programVKey, bytes publicValues, bytes proofBytes)`
+//! style call. This module writes a fixture containing those fields plus a
+//! pre-ABI-encoded calldata blob, so a contract test can replay the call
+//! directly.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ProofSystem, SP1ProofWithPublicValues, SP1VerifyingKey};
+
+/// Errors produced while creating a Solidity verifier fixture.
+#[derive(Debug)]
+pub enum SolidityFixtureError {
+    /// `system` is not verifiable on-chain.
+    UnsupportedSystem(ProofSystem),
+}
+
+impl fmt::Display for SolidityFixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolidityFixtureError::UnsupportedSystem(system) => {
+                write!(f, "{system:?} proofs are not EVM-verifiable; only Groth16 and Plonk are")
+            }
+        }
+    }
+}
+
+impl Error for SolidityFixtureError {}
+
+/// A Solidity verifier calldata fixture.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SolidityFixture {
+    /// The 32-byte program verifying-key commitment (`bytes32`).
+    #[serde(with = "crate::proof_serde")]
+    pub vkey_hash: Vec<u8>,
+    /// The raw public values passed to `verifyProof`.
+    #[serde(with = "crate::proof_serde")]
+    pub public_values: Vec<u8>,
+    /// The raw proof bytes passed to `verifyProof`.
+    #[serde(with = "crate::proof_serde")]
+    pub proof: Vec<u8>,
+    /// ABI-encoded calldata for `verifyProof(bytes32, bytes, bytes)`.
+    #[serde(with = "crate::proof_serde")]
+    pub calldata: Vec<u8>,
+    /// The proof system used.
+    pub system: ProofSystem,
+}
+
+/// Writes an EVM verifier calldata fixture for `proof`/`vk` under the given `system`.
+///
+/// Only `Groth16` and `Plonk` proofs are EVM-verifiable; `STARK` is rejected.
+pub fn create_solidity_fixture(
+    proof: &SP1ProofWithPublicValues,
+    vk: &SP1VerifyingKey,
+    system: ProofSystem,
+) -> Result<(), Box<dyn Error>> {
+    if system == ProofSystem::STARK {
+        return Err(Box::new(SolidityFixtureError::UnsupportedSystem(system)));
+    }
+
+    let vkey_hash = vk.hash_bytes32();
+    let calldata = encode_verify_proof_calldata(&vkey_hash, &proof.public_values, &proof.proof);
+
+    let fixture = SolidityFixture {
+        vkey_hash: vkey_hash.to_vec(),
+        public_values: proof.public_values.clone(),
+        proof: proof.proof.clone(),
+        calldata,
+        system,
+    };
+
+    let fixtures_dir = PathBuf::from("fixtures");
+    fs::create_dir_all(&fixtures_dir)?;
+
+    let filename = match system {
+        ProofSystem::Plonk => "solidity_fixture_plonk.json",
+        ProofSystem::Groth16 => "solidity_fixture_groth16.json",
+        ProofSystem::STARK => unreachable!("STARK is rejected above"),
+    };
+    let fixture_path = fixtures_dir.join(filename);
+
+    let json = serde_json::to_string_pretty(&fixture)?;
+    fs::write(&fixture_path, json)?;
+
+    println!("Solidity fixture created at: {}", fixture_path.display());
+
+    Ok(())
+}
+
+/// ABI-encodes a `verifyProof(bytes32, bytes, bytes)` call's arguments.
+fn encode_verify_proof_calldata(vkey_hash: &[u8; 32], public_values: &[u8], proof: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(vkey_hash);
+
+    let head_len = 3 * 32;
+    let offset_public_values = head_len;
+    let offset_proof = head_len + dynamic_len(public_values.len());
+
+    out.extend_from_slice(&word_from_usize(offset_public_values));
+    out.extend_from_slice(&word_from_usize(offset_proof));
+
+    append_dynamic_bytes(&mut out, public_values);
+    append_dynamic_bytes(&mut out, proof);
+
+    out
+}
+
+fn dynamic_len(len: usize) -> usize {
+    32 + pad32(len)
+}
+
+fn pad32(len: usize) -> usize {
+    len.div_ceil(32) * 32
+}
+
+fn word_from_usize(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn append_dynamic_bytes(out: &mut Vec<u8>, data: &[u8]) {
+    out.extend_from_slice(&word_from_usize(data.len()));
+    out.extend_from_slice(data);
+    out.resize(out.len() + (pad32(data.len()) - data.len()), 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_stark() {
+        let proof = SP1ProofWithPublicValues { proof: vec![1], public_values: vec![2] };
+        let vk = SP1VerifyingKey { vk: vec![3] };
+        let err = create_solidity_fixture(&proof, &vk, ProofSystem::STARK).unwrap_err();
+        assert!(err.to_string().contains("not EVM-verifiable"));
+    }
+
+    #[test]
+    fn calldata_head_encodes_offsets() {
+        let vkey_hash = [7u8; 32];
+        let calldata = encode_verify_proof_calldata(&vkey_hash, &[1, 2, 3], &[4, 5]);
+        assert_eq!(&calldata[0..32], &vkey_hash);
+
+        let offset_pv = usize::from_be_bytes(calldata[32 + 24..64].try_into().unwrap());
+        assert_eq!(offset_pv, 96);
+
+        let offset_proof = usize::from_be_bytes(calldata[64 + 24..96].try_into().unwrap());
+        assert_eq!(offset_proof, 96 + 32 + 32);
+    }
+}
